@@ -3,13 +3,263 @@
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Display;
+use std::ops::Range;
+
+/// A typed value that can be substituted into a pattern.
+///
+/// Numbers and strings are kept distinct because format specs (width,
+/// precision, sign, zero-padding) behave differently for each, mirroring
+/// how Rust's own `format!` treats them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value<'a> {
+    /// A borrowed string slice, substituted and (optionally) truncated/padded as text.
+    Str(&'a str),
+    /// A signed integer, substituted and (optionally) signed/padded as a number.
+    Int(i64),
+    /// A floating point number. A bare `{key}` substitutes it in its shortest
+    /// form (as `Display` does); under a format spec without a precision it is
+    /// written with 6 decimal digits.
+    Float(f64),
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(value: &'a str) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl From<i64> for Value<'_> {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<f64> for Value<'_> {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl Display for Value<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Str(s) => write!(f, "{}", s),
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+/// A parsed `{key:spec}` format spec, following the grammar
+/// `[[fill]align][sign]['0'][width]['.' precision]`.
+#[derive(Debug, Clone)]
+struct FormatSpec {
+    fill: char,
+    align: Option<Align>,
+    sign_plus: bool,
+    zero_pad: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+}
+
+impl FormatSpec {
+    fn parse(spec: &str) -> Result<Self, String> {
+        let chars: Vec<char> = spec.chars().collect();
+        let mut i = 0;
+        let mut fill = ' ';
+        let mut align = None;
+
+        if chars.len() >= 2 && Self::is_align(chars[1]) {
+            fill = chars[0];
+            align = Some(Self::to_align(chars[1]));
+            i = 2;
+        } else if !chars.is_empty() && Self::is_align(chars[0]) {
+            align = Some(Self::to_align(chars[0]));
+            i = 1;
+        }
+
+        let sign_plus = if i < chars.len() && chars[i] == '+' {
+            i += 1;
+            true
+        } else {
+            false
+        };
+
+        let zero_pad = if i < chars.len() && chars[i] == '0' {
+            i += 1;
+            true
+        } else {
+            false
+        };
+
+        let width_start = i;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        let width = if i > width_start {
+            Some(
+                chars[width_start..i]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>()
+                    .map_err(|_| "Invalid width".to_string())?,
+            )
+        } else {
+            None
+        };
+
+        let precision = if i < chars.len() && chars[i] == '.' {
+            i += 1;
+            let precision_start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i == precision_start {
+                return Err("Expected digits after '.' in precision".to_string());
+            }
+            Some(
+                chars[precision_start..i]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<usize>()
+                    .map_err(|_| "Invalid precision".to_string())?,
+            )
+        } else {
+            None
+        };
+
+        if i != chars.len() {
+            return Err(format!(
+                "Unexpected character '{}' in format spec \"{}\"",
+                chars[i], spec
+            ));
+        }
+
+        Ok(FormatSpec {
+            fill,
+            align,
+            sign_plus,
+            zero_pad,
+            width,
+            precision,
+        })
+    }
+
+    fn is_align(c: char) -> bool {
+        c == '<' || c == '^' || c == '>'
+    }
+
+    fn to_align(c: char) -> Align {
+        match c {
+            '<' => Align::Left,
+            '^' => Align::Center,
+            '>' => Align::Right,
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn pad(text: &str, fill: char, align: Align, width: usize) -> String {
+    let len = text.chars().count();
+    if len >= width {
+        return text.to_string();
+    }
+    let total_pad = width - len;
+    match align {
+        Align::Left => format!("{}{}", text, fill.to_string().repeat(total_pad)),
+        Align::Right => format!("{}{}", fill.to_string().repeat(total_pad), text),
+        Align::Center => {
+            let left = total_pad / 2;
+            let right = total_pad - left;
+            format!(
+                "{}{}{}",
+                fill.to_string().repeat(left),
+                text,
+                fill.to_string().repeat(right)
+            )
+        }
+    }
+}
+
+fn pad_numeric(sign: &str, digits: &str, spec: &FormatSpec) -> String {
+    let width = match spec.width {
+        Some(width) => width,
+        None => return format!("{}{}", sign, digits),
+    };
+    if spec.zero_pad {
+        // The `0` flag always wins over an explicit fill/align for numeric
+        // values, matching `format!` (e.g. `format!("{:^05}", 5)` is "00005").
+        let body_len = sign.chars().count() + digits.chars().count();
+        let total_pad = width.saturating_sub(body_len);
+        format!("{}{}{}", sign, "0".repeat(total_pad), digits)
+    } else {
+        let align = spec.align.unwrap_or(Align::Right);
+        pad(&format!("{}{}", sign, digits), spec.fill, align, width)
+    }
+}
+
+fn apply_spec(value: &Value, spec: &FormatSpec) -> String {
+    match value {
+        Value::Str(s) => {
+            let text = match spec.precision {
+                Some(p) => s.chars().take(p).collect::<String>(),
+                None => s.to_string(),
+            };
+            match spec.width {
+                Some(width) => pad(&text, spec.fill, spec.align.unwrap_or(Align::Left), width),
+                None => text,
+            }
+        }
+        Value::Int(n) => {
+            let sign = if *n < 0 {
+                "-"
+            } else if spec.sign_plus {
+                "+"
+            } else {
+                ""
+            };
+            let digits = n.unsigned_abs().to_string();
+            pad_numeric(sign, &digits, spec)
+        }
+        Value::Float(x) => {
+            let precision = spec.precision.unwrap_or(6);
+            let sign = if x.is_sign_negative() {
+                "-"
+            } else if spec.sign_plus {
+                "+"
+            } else {
+                ""
+            };
+            let digits = format!("{:.*}", precision, x.abs());
+            pad_numeric(sign, &digits, spec)
+        }
+    }
+}
 
 /// Lightweight, dynamic, Python-styled string formatting (Only support `String`,
 /// `{key}` patterns). It only needs `std` to work.
 ///
 /// Escape patterns are `{{` and `}}`.
 ///
-/// It returns the formatted string.
+/// A placeholder may carry a format spec after a colon, e.g. `{key:spec}`,
+/// following the same grammar as Rust's own `format!`:
+/// `[[fill]align][sign]['0'][width]['.' precision]`, where `align` is one of
+/// `<^>`, `fill` is any char preceding it other than `{` or `}` (those are
+/// read as braces of the pattern), `sign` is `+`, a leading `0`
+/// zero-pads numbers, `width`/`precision` are decimal integers. `width` pads
+/// the value (left-aligned for strings, right-aligned for numbers by
+/// default) and `precision` truncates strings or sets decimal digits for
+/// floats.
+///
+/// It returns the formatted string, stopping at the first error. See
+/// [`dynamic_format_all`] to collect every error in one pass instead.
 ///
 /// ## Errors
 ///
@@ -17,28 +267,27 @@ use std::fmt::Display;
 ///    is not found in `dictionary`.
 /// 2. Error kind `DynamicFormatErrorKind::TokenError` if there is any
 ///    unmatched bracket (`{` or `}`)
+/// 3. Error kind `DynamicFormatErrorKind::SpecError` if the format spec after
+///    `:` cannot be parsed.
 ///
 /// ## Examples
 ///
 /// ```
-/// use dyn_formatting::dynamic_format;
+/// use dyn_formatting::{dynamic_format, Value};
 /// assert_eq!(
 ///     dynamic_format(
 ///         "I'm {name}. I'm {age} years old now.",
-///         &[("name", "ABC"), ("age", "20")].into()
+///         &[("name", Value::Str("ABC")), ("age", Value::Int(20))].into()
 ///     ).unwrap(),
 ///     "I'm ABC. I'm 20 years old now.".to_string()
 /// );
 /// ```
 ///
 /// ```
-/// use dyn_formatting::dynamic_format;
+/// use dyn_formatting::{dynamic_format, Value};
 /// use std::collections::HashMap;
 ///
-/// let value_age = (15).to_string(); // Make lifetime long enough
-/// let dictionary = HashMap::from([
-///     ("age", value_age.as_str()),
-/// ]);
+/// let dictionary = HashMap::from([("age", Value::Int(15))]);
 /// assert_eq!(
 ///     dynamic_format("{{{age} }}{age}", &dictionary).unwrap(),
 ///     "{15 }15"
@@ -46,106 +295,406 @@ use std::fmt::Display;
 /// ```
 ///
 /// ```
-/// use dyn_formatting::dynamic_format;
+/// use dyn_formatting::{dynamic_format, Value};
 /// assert!(
 ///     dynamic_format(
 ///         "I'm {name}. I'm {age} years old now.",
-///         &[("name", "ABC")].into()
+///         &[("name", Value::Str("ABC"))].into()
 ///     ).is_err() // Key error
 /// );
 /// ```
 ///
 /// ```
-/// use dyn_formatting::dynamic_format;
+/// use dyn_formatting::{dynamic_format, Value};
 /// assert!(
 ///     dynamic_format(
 ///         "I'm {name{name}}.",
-///         &[("name", "ABC")].into()
+///         &[("name", Value::Str("ABC"))].into()
 ///     ).is_err() // Token error: '{' unmatched.
 /// );
 /// ```
-
+///
+/// ```
+/// use dyn_formatting::{dynamic_format, Value};
+/// let result = dynamic_format(
+///     "{name:>10}|{price:08.2}|{label:-^20}",
+///     &[
+///         ("name", Value::Str("ABC")),
+///         ("price", Value::Float(3.5)),
+///         ("label", Value::Str("hi")),
+///     ].into()
+/// ).unwrap();
+/// assert_eq!(result, format!("{:>10}|{:08.2}|{:-^20}", "ABC", 3.5, "hi"));
+/// ```
 pub fn dynamic_format(
     pattern: &str,
-    dictionary: &HashMap<&str, &str>,
+    dictionary: &HashMap<&str, Value>,
+) -> Result<String, Box<DynamicFormatError>> {
+    dynamic_format_all(pattern, dictionary).map_err(|mut errors| Box::new(errors.remove(0)))
+}
+
+/// Like [`dynamic_format`], but never stops at the first error.
+///
+/// The whole pattern is scanned and every token, key and spec error is
+/// collected (in the order the scan detects them: left to right, except that
+/// an unmatched `}` is only reported once the next `}` or the end of the
+/// pattern is reached, so it can follow an error for a `{` after it) instead
+/// of bailing out on the first one, similar to how a compiler reports all the errors it can find in one
+/// pass. An unresolved placeholder is left as its original `{key}` or
+/// `{key:spec}` text in the (discarded-on-error) output, so the scan can
+/// keep tracking positions for the rest of the pattern.
+///
+/// Returns `Ok` only if every placeholder resolved cleanly; otherwise `Err`
+/// with every error that was found.
+///
+/// ## Examples
+///
+/// ```
+/// use dyn_formatting::dynamic_format_all;
+/// use dyn_formatting::Value;
+///
+/// assert_eq!(
+///     dynamic_format_all("{a}-{b}", &[("a", Value::Str("1")), ("b", Value::Str("2"))].into())
+///         .unwrap(),
+///     "1-2".to_string()
+/// );
+///
+/// let errors = dynamic_format_all("{a}-{b}-{c", &[("a", Value::Str("1"))].into()).unwrap_err();
+/// assert_eq!(errors.len(), 2); // Key error for "b", token error for the unmatched "{c".
+/// ```
+pub fn dynamic_format_all(
+    pattern: &str,
+    dictionary: &HashMap<&str, Value>,
+) -> Result<String, Vec<DynamicFormatError>> {
+    let mut resolver = DictResolver { dictionary };
+    let (ans, errors) = scan(pattern, &mut resolver);
+    if errors.is_empty() {
+        Ok(ans)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Like [`dynamic_format`], but resolves placeholders positionally instead of by name.
+///
+/// `{}` consumes the next argument from `args`, advancing an internal counter each
+/// time it is used; `{0}`, `{1}`, ... index `args` directly without advancing that
+/// counter. Mixing the two follows the same rule as `format!`: an explicit `{N}`
+/// does not affect what the next `{}` resolves to. Format specs (`{0:>10}`) are
+/// supported exactly as in [`dynamic_format`].
+///
+/// ## Errors
+///
+/// Token and spec errors are reported as in [`dynamic_format`]; `KeyError` is
+/// never produced. Instead, an out-of-range index (explicit or implicit)
+/// produces `DynamicFormatErrorKind::IndexError`, and a key that is neither
+/// empty nor an unsigned integer produces `DynamicFormatErrorKind::InvalidKeyError`.
+///
+/// ## Examples
+///
+/// ```
+/// use dyn_formatting::{dynamic_format_args, Value};
+/// assert_eq!(
+///     dynamic_format_args("{1} {} {0}", &[Value::Str("a"), Value::Str("b")]).unwrap(),
+///     "b a a".to_string()
+/// );
+/// ```
+pub fn dynamic_format_args(
+    pattern: &str,
+    args: &[Value],
 ) -> Result<String, Box<DynamicFormatError>> {
-    if pattern.find('{') == None && pattern.find('}') == None {
-        return Ok(pattern.to_string());
+    dynamic_format_args_all(pattern, args).map_err(|mut errors| Box::new(errors.remove(0)))
+}
+
+/// The [`dynamic_format_all`] counterpart of [`dynamic_format_args`]: collects every
+/// error instead of stopping at the first one.
+pub fn dynamic_format_args_all(
+    pattern: &str,
+    args: &[Value],
+) -> Result<String, Vec<DynamicFormatError>> {
+    let mut resolver = ArgsResolver { args, next: 0 };
+    let (ans, errors) = scan(pattern, &mut resolver);
+    if errors.is_empty() {
+        Ok(ans)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Why a placeholder's key could not be resolved to a [`Value`].
+#[allow(clippy::enum_variant_names)]
+enum ResolveError {
+    /// No such named key in the dictionary.
+    KeyError { entries: Vec<(String, String)> },
+    /// The (explicit or implicit) positional index is out of range.
+    IndexError { index: usize, len: usize },
+    /// An explicit key could not be parsed as a positional index.
+    InvalidKeyError { key: String },
+}
+
+/// Resolves a placeholder's key (the text before `:`, e.g. `name`, `0` or empty
+/// for an implicit positional arg) to a [`Value`]. Implemented once for named
+/// lookups and once for positional/implicit arguments, so [`scan`] can stay
+/// agnostic of where values come from.
+trait Resolver {
+    fn resolve(&mut self, key: &str) -> Result<Value<'_>, ResolveError>;
+}
+
+/// Resolves `{key}` placeholders against a named dictionary.
+struct DictResolver<'a, 'd> {
+    dictionary: &'d HashMap<&'d str, Value<'a>>,
+}
+
+impl Resolver for DictResolver<'_, '_> {
+    fn resolve(&mut self, key: &str) -> Result<Value<'_>, ResolveError> {
+        self.dictionary.get(key).copied().ok_or_else(|| ResolveError::KeyError {
+            entries: self
+                .dictionary
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        })
+    }
+}
+
+/// Resolves `{}` (implicit, advances `next`) and `{N}` (explicit, does not
+/// advance `next`) placeholders against an ordered argument slice.
+struct ArgsResolver<'a> {
+    args: &'a [Value<'a>],
+    next: usize,
+}
+
+impl Resolver for ArgsResolver<'_> {
+    fn resolve(&mut self, key: &str) -> Result<Value<'_>, ResolveError> {
+        let index = if key.is_empty() {
+            let index = self.next;
+            self.next += 1;
+            index
+        } else {
+            match key.parse::<usize>() {
+                Ok(index) => index,
+                Err(_) => {
+                    return Err(ResolveError::InvalidKeyError {
+                        key: key.to_string(),
+                    })
+                }
+            }
+        };
+        self.args.get(index).copied().ok_or(ResolveError::IndexError {
+            index,
+            len: self.args.len(),
+        })
+    }
+}
+
+/// Reconstructs the raw `{key}` / `{key:spec}` text of a not-yet-closed placeholder,
+/// used to recover from errors without losing the original characters.
+fn raw_placeholder(key: &str, spec: &str, in_spec: bool) -> String {
+    let mut text = String::with_capacity(key.len() + spec.len() + 2);
+    text.push('{');
+    text.push_str(key);
+    if in_spec {
+        text.push(':');
+        text.push_str(spec);
+    }
+    text
+}
+
+/// Resolves a placeholder once its closing `}` (at `close_pos`) is reached, pushing
+/// either the substituted value or (on error) the raw placeholder text, and resets
+/// the scanner state for the next one.
+#[allow(clippy::too_many_arguments)]
+fn close_placeholder(
+    close_pos: usize,
+    ans: &mut String,
+    errors: &mut Vec<DynamicFormatError>,
+    pattern: &str,
+    byte_offsets: &[usize],
+    resolver: &mut dyn Resolver,
+    left_brace: &mut (bool, usize),
+    key: &mut String,
+    spec: &mut String,
+    in_spec: &mut bool,
+) {
+    let open_pos = left_brace.1;
+    let range = byte_offsets[open_pos]..byte_offsets[close_pos + 1];
+    match resolver.resolve(key) {
+        Ok(value) if spec.is_empty() => ans.push_str(&value.to_string()),
+        Ok(value) => match FormatSpec::parse(spec) {
+            Ok(parsed) => ans.push_str(&apply_spec(&value, &parsed)),
+            Err(desc) => {
+                errors.push(DynamicFormatError {
+                    pattern: pattern.to_string(),
+                    pos: open_pos,
+                    range,
+                    kind: DynamicFormatErrorKind::SpecError { desc },
+                });
+                ans.push_str(&raw_placeholder(key, spec, *in_spec));
+                ans.push('}');
+            }
+        },
+        Err(ResolveError::KeyError { entries }) => {
+            errors.push(DynamicFormatError {
+                pattern: pattern.to_string(),
+                pos: open_pos,
+                range,
+                kind: DynamicFormatErrorKind::KeyError {
+                    key: key.clone(),
+                    entries,
+                },
+            });
+            ans.push_str(&raw_placeholder(key, spec, *in_spec));
+            ans.push('}');
+        }
+        Err(ResolveError::IndexError { index, len }) => {
+            errors.push(DynamicFormatError {
+                pattern: pattern.to_string(),
+                pos: open_pos,
+                range,
+                kind: DynamicFormatErrorKind::IndexError { index, len },
+            });
+            ans.push_str(&raw_placeholder(key, spec, *in_spec));
+            ans.push('}');
+        }
+        Err(ResolveError::InvalidKeyError { key: bad_key }) => {
+            errors.push(DynamicFormatError {
+                pattern: pattern.to_string(),
+                pos: open_pos,
+                range,
+                kind: DynamicFormatErrorKind::InvalidKeyError { key: bad_key },
+            });
+            ans.push_str(&raw_placeholder(key, spec, *in_spec));
+            ans.push('}');
+        }
     }
+    key.clear();
+    spec.clear();
+    *in_spec = false;
+    *left_brace = (false, 0);
+}
+
+/// The shared scanning engine behind [`dynamic_format`] and [`dynamic_format_all`].
+///
+/// Returns the best-effort substituted string together with every error found.
+/// On a recovered token error the offending brace (and any placeholder text
+/// buffered for it) is flushed back into the output as plain text, so scanning
+/// can continue and keep reporting accurate positions for the rest of the pattern.
+fn scan(pattern: &str, resolver: &mut dyn Resolver) -> (String, Vec<DynamicFormatError>) {
     let chars: Vec<char> = pattern.chars().collect();
+    // `chars` is indexed by char count, but `Range<usize>` is meant to slice
+    // `pattern` itself, which is indexed by byte count; this maps one to the
+    // other, with one extra trailing entry for the end-of-string position.
+    let byte_offsets: Vec<usize> = pattern
+        .char_indices()
+        .map(|(b, _)| b)
+        .chain(std::iter::once(pattern.len()))
+        .collect();
     let mut ans: String = String::with_capacity(pattern.len());
+    let mut errors: Vec<DynamicFormatError> = Vec::new();
     let mut left_brace = (false, 0usize);
     let mut right_brace = (false, 0usize);
     let mut key = String::with_capacity(16);
+    let mut spec = String::with_capacity(16);
+    let mut in_spec = false;
 
     macro_rules! token_error {
-        ($pos: expr, $msg: expr) => {
-            return Err(Box::new(DynamicFormatError {
+        ($pos: expr) => {
+            errors.push(DynamicFormatError {
                 pattern: pattern.to_string(),
                 pos: $pos,
-                kind: DynamicFormatErrorKind::TokenError { desc: $msg.into() },
-            }));
+                range: byte_offsets[$pos]..byte_offsets[$pos + 1],
+                kind: DynamicFormatErrorKind::TokenError {
+                    desc: format!("Unmatched token '{}'", chars[$pos]),
+                },
+            });
         };
     }
 
-    for (i, c) in chars.iter().enumerate() {
-        if *c == '{' {
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '{' {
             if left_brace.0 {
                 if left_brace.1 + 1 == i {
                     ans.push('{');
                     left_brace = (false, 0);
                 } else {
-                    token_error!(left_brace.1, "Unmatched token '{'");
+                    token_error!(left_brace.1);
+                    ans.push_str(&raw_placeholder(&key, &spec, in_spec));
+                    key.clear();
+                    spec.clear();
+                    in_spec = false;
+                    left_brace = (true, i);
                 }
             } else {
                 left_brace = (true, i);
             }
-        } else if *c == '}' {
+        } else if c == '}' {
             if right_brace.0 {
                 if right_brace.1 + 1 == i {
                     ans.push('}');
                     right_brace = (false, 0);
                 } else {
-                    token_error!(right_brace.1, "Unmatched token '}'");
+                    token_error!(right_brace.1);
+                    ans.push('}');
+                    right_brace = (false, 0);
+                    if left_brace.0 {
+                        close_placeholder(
+                            i,
+                            &mut ans,
+                            &mut errors,
+                            pattern,
+                            &byte_offsets,
+                            resolver,
+                            &mut left_brace,
+                            &mut key,
+                            &mut spec,
+                            &mut in_spec,
+                        );
+                    } else {
+                        right_brace = (true, i);
+                    }
                 }
             } else if left_brace.0 {
-                if let Some(s) = dictionary.get(key.as_str()) {
-                    ans.push_str(s);
-                } else {
-                    return Err(Box::new(DynamicFormatError {
-                        pattern: pattern.to_string(),
-                        pos: left_brace.1,
-                        kind: DynamicFormatErrorKind::KeyError {
-                            key,
-                            entries: dictionary
-                                .iter()
-                                .map(|s| (s.0.to_string(), s.1.to_string()))
-                                .collect(),
-                        },
-                    }));
-                }
-                key.clear();
-                left_brace = (false, 0);
+                close_placeholder(
+                    i,
+                    &mut ans,
+                    &mut errors,
+                    pattern,
+                    &byte_offsets,
+                    resolver,
+                    &mut left_brace,
+                    &mut key,
+                    &mut spec,
+                    &mut in_spec,
+                );
             } else {
                 right_brace = (true, i);
             }
-        } else {
-            if left_brace.0 {
-                key.push(*c);
+        } else if left_brace.0 {
+            if in_spec {
+                spec.push(c);
+            } else if c == ':' {
+                in_spec = true;
             } else {
-                ans.push(*c);
+                key.push(c);
             }
+        } else {
+            ans.push(c);
         }
+        i += 1;
     }
 
     if left_brace.0 {
-        token_error!(left_brace.1, "Unmatched token '{'");
+        token_error!(left_brace.1);
+        ans.push_str(&raw_placeholder(&key, &spec, in_spec));
     }
     if right_brace.0 {
-        token_error!(right_brace.1, "Unmatched token '}'");
+        token_error!(right_brace.1);
+        ans.push('}');
     }
 
-    Ok(ans)
+    (ans, errors)
 }
 
 /// Error types during dynamic formatting.
@@ -156,6 +705,10 @@ pub struct DynamicFormatError {
     /// The position (index) where the error occurs.
     /// Start from 0 itself, but from 1 when formatting.
     pub pos: usize,
+    /// The byte range (start..end) over `pattern` covering the full offending
+    /// placeholder or unmatched brace, for tools that want to slice or
+    /// highlight it directly.
+    pub range: Range<usize>,
     /// Error kind.
     pub kind: DynamicFormatErrorKind,
 }
@@ -175,6 +728,26 @@ pub enum DynamicFormatErrorKind {
         /// The entries of the dictionary. It is used to provide help information.
         entries: Vec<(String, String)>,
     },
+    /// The format spec (after `:` in braces) could not be parsed.
+    SpecError {
+        /// The brief description of error.
+        desc: String,
+    },
+    /// The positional index (explicit `{N}` or the next implicit `{}`) is out
+    /// of range for the arguments supplied to `dynamic_format_args`.
+    IndexError {
+        /// The index that was requested.
+        index: usize,
+        /// The number of arguments that were supplied.
+        len: usize,
+    },
+    /// The key (in braces) is not a valid positional index, e.g. `{abc}` when
+    /// formatting with `dynamic_format_args`/`dynamic_format_args_all`, which
+    /// only accept an empty key or an unsigned integer.
+    InvalidKeyError {
+        /// The key text that failed to parse as an index.
+        key: String,
+    },
 }
 
 impl Display for DynamicFormatError {
@@ -200,6 +773,26 @@ impl Display for DynamicFormatError {
                     .collect::<Vec<_>>()
                     .join("\n"),
             ),
+            DynamicFormatErrorKind::SpecError { desc } => write!(
+                f,
+                "Parse arguments failed: Spec Error ({}) when \
+                parsing pattern \"{}\" at pos {}.",
+                desc, self.pattern, self.pos + 1
+            ),
+            DynamicFormatErrorKind::IndexError { index, len } => write!(
+                f,
+                "Parse arguments failed: Index Out Of Range \
+                (index: {}, {} argument(s) supplied) when \
+                parsing pattern \"{}\" at pos {}.",
+                index, len, self.pattern, self.pos + 1
+            ),
+            DynamicFormatErrorKind::InvalidKeyError { key } => write!(
+                f,
+                "Parse arguments failed: Invalid Key \
+                (key: \"{}\" is not an empty key or a positional index) when \
+                parsing pattern \"{}\" at pos {}.",
+                key, self.pattern, self.pos + 1
+            ),
         }
     }
 }
@@ -225,11 +818,11 @@ mod tests {
             "abcdefg".to_string()
         );
         assert_eq!(
-            dynamic_format!("abc", [("abc", "")]).unwrap(),
+            dynamic_format!("abc", [("abc", Value::Str(""))]).unwrap(),
             "abc".to_string()
         );
         assert_eq!(
-            dynamic_format!("we-have", [("we", "")]).unwrap(),
+            dynamic_format!("we-have", [("we", Value::Str(""))]).unwrap(),
             "we-have".to_string()
         );
     }
@@ -238,7 +831,7 @@ mod tests {
     fn test_escape() {
         assert_eq!(dynamic_format!("}}", []).unwrap(), "}".to_string());
         assert_eq!(
-            dynamic_format!("{{ab}}", [("ab", "1")]).unwrap(),
+            dynamic_format!("{{ab}}", [("ab", Value::Str("1"))]).unwrap(),
             "{ab}".to_string()
         );
         assert_eq!(dynamic_format!("{{234", []).unwrap(), "{234".to_string());
@@ -248,15 +841,23 @@ mod tests {
     #[test]
     fn test_replace() {
         assert_eq!(
-            dynamic_format!("{ab}", [("ab", "1")]).unwrap(),
+            dynamic_format!("{ab}", [("ab", Value::Str("1"))]).unwrap(),
             "1".to_string()
         );
         assert_eq!(
-            dynamic_format!("1{a}32{a}4", [("a", "555"), ("b", "")]).unwrap(),
+            dynamic_format!(
+                "1{a}32{a}4",
+                [("a", Value::Str("555")), ("b", Value::Str(""))]
+            )
+            .unwrap(),
             "1555325554".to_string()
         );
         assert_eq!(
-            dynamic_format!("{key1}-{key2}", [("key1", "0"), ("key2", "a")]).unwrap(),
+            dynamic_format!(
+                "{key1}-{key2}",
+                [("key1", Value::Str("0")), ("key2", Value::Str("a"))]
+            )
+            .unwrap(),
             "0-a".to_string()
         );
     }
@@ -264,26 +865,31 @@ mod tests {
     #[test]
     fn test_mixed() {
         assert_eq!(
-            dynamic_format!("{{{a}", [("a", "1")]).unwrap(),
+            dynamic_format!("{{{a}", [("a", Value::Str("1"))]).unwrap(),
             "{1".to_string()
         );
         assert_eq!(
-            dynamic_format!("{{|{k}}}", [("k", "x123")]).unwrap(),
+            dynamic_format!("{{|{k}}}", [("k", Value::Str("x123"))]).unwrap(),
             "{|x123}".to_string()
         );
         assert_eq!(
-            dynamic_format!("{{{key1}}}-}}}}{key2}", [("key1", "0"), ("key2", "a")]).unwrap(),
+            dynamic_format!(
+                "{{{key1}}}-}}}}{key2}",
+                [("key1", Value::Str("0")), ("key2", Value::Str("a"))]
+            )
+            .unwrap(),
             "{0}-}}a".to_string()
         );
     }
 
     #[test]
     fn test_key_error() {
-        match *dynamic_format!("{abc}", [("abd", "1")]).unwrap_err() {
+        match *dynamic_format!("{abc}", [("abd", Value::Str("1"))]).unwrap_err() {
             DynamicFormatError {
                 pattern,
                 pos,
                 kind: KeyError { key, entries },
+                ..
             } => {
                 assert_eq!(pattern.as_str(), "{abc}");
                 assert_eq!(key, "abc");
@@ -292,7 +898,12 @@ mod tests {
             }
             _ => unreachable!(),
         }
-        match *dynamic_format!("234{ac}{ab}", [("ac", "1"), ("aa", ".")]).unwrap_err() {
+        match *dynamic_format!(
+            "234{ac}{ab}",
+            [("ac", Value::Str("1")), ("aa", Value::Str("."))]
+        )
+        .unwrap_err()
+        {
             DynamicFormatError {
                 pos,
                 kind: KeyError { key, .. },
@@ -307,11 +918,12 @@ mod tests {
 
     #[test]
     fn test_token_error() {
-        match *dynamic_format!("{abc", [("abc", "1")]).unwrap_err() {
+        match *dynamic_format!("{abc", [("abc", Value::Str("1"))]).unwrap_err() {
             DynamicFormatError {
                 pattern,
                 pos,
                 kind: TokenError { desc },
+                ..
             } => {
                 assert_eq!(pattern.as_str(), "{abc");
                 assert!(desc.contains("'{'"));
@@ -359,7 +971,200 @@ mod tests {
         println!("{}", dynamic_format!("name}3}24", []).unwrap_err());
         println!(
             "{}",
-            dynamic_format!("234{ac}{ab}", [("ac", "1"), ("aa", ".")]).unwrap_err()
+            dynamic_format!(
+                "234{ac}{ab}",
+                [("ac", Value::Str("1")), ("aa", Value::Str("."))]
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_format_spec() {
+        assert_eq!(
+            dynamic_format!("{name:>10}", [("name", Value::Str("ABC"))]).unwrap(),
+            format!("{:>10}", "ABC")
+        );
+        assert_eq!(
+            dynamic_format!("{name:<10}", [("name", Value::Str("ABC"))]).unwrap(),
+            format!("{:<10}", "ABC")
+        );
+        assert_eq!(
+            dynamic_format!("{label:-^20}", [("label", Value::Str("hi"))]).unwrap(),
+            format!("{:-^20}", "hi")
+        );
+        assert_eq!(
+            dynamic_format!("{price:08.2}", [("price", Value::Float(3.5))]).unwrap(),
+            format!("{:08.2}", 3.5)
+        );
+        assert_eq!(
+            dynamic_format!("{n:+}", [("n", Value::Int(5))]).unwrap(),
+            format!("{:+}", 5)
+        );
+        assert_eq!(
+            dynamic_format!("{n:5}", [("n", Value::Int(-5))]).unwrap(),
+            format!("{:5}", -5)
+        );
+        assert_eq!(
+            dynamic_format!("{s:.3}", [("s", Value::Str("abcdef"))]).unwrap(),
+            "abc".to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_spec_zero_flag_overrides_explicit_align() {
+        assert_eq!(
+            dynamic_format!("{n:^05}", [("n", Value::Int(5))]).unwrap(),
+            format!("{:^05}", 5)
+        );
+        assert_eq!(
+            dynamic_format!("{n:*<08}", [("n", Value::Int(5))]).unwrap(),
+            format!("{:*<08}", 5)
+        );
+        assert_eq!(
+            dynamic_format!("{n:^05}", [("n", Value::Int(-5))]).unwrap(),
+            format!("{:^05}", -5)
+        );
+    }
+
+    #[test]
+    fn test_format_spec_zero_flag_is_noop_for_strings() {
+        assert_eq!(
+            dynamic_format!("{s:05}", [("s", Value::Str("ab"))]).unwrap(),
+            format!("{:05}", "ab")
+        );
+    }
+
+    #[test]
+    fn test_spec_error() {
+        match *dynamic_format!("{name:>10x}", [("name", Value::Str("ABC"))]).unwrap_err() {
+            DynamicFormatError {
+                pos,
+                kind: SpecError { .. },
+                ..
+            } => {
+                assert_eq!(pos, 0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_dynamic_format_all_ok() {
+        assert_eq!(
+            dynamic_format_all(
+                "{a}-{b}",
+                &[("a", Value::Str("1")), ("b", Value::Str("2"))].into()
+            )
+            .unwrap(),
+            "1-2".to_string()
+        );
+    }
+
+    #[test]
+    fn test_dynamic_format_all_collects_every_error() {
+        let errors = dynamic_format_all("{a}-{b}-{c", &[("a", Value::Str("1"))].into()).unwrap_err();
+
+        assert_eq!(errors.len(), 2);
+        match &errors[0].kind {
+            KeyError { key, .. } => assert_eq!(key, "b"),
+            _ => unreachable!(),
+        }
+        assert_eq!(errors[0].range, 4..7);
+        match &errors[1].kind {
+            TokenError { desc } => assert!(desc.contains("'{'")),
+            _ => unreachable!(),
+        }
+        assert_eq!(errors[1].range, 8..9);
+    }
+
+    #[test]
+    fn test_dynamic_format_all_range_is_byte_indexed() {
+        let pattern = "café{missing}";
+        let errors = dynamic_format_all(pattern, &HashMap::new()).unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(&pattern[errors[0].range.clone()], "{missing}");
+    }
+
+    #[test]
+    fn test_dynamic_format_returns_first_of_many_errors() {
+        match *dynamic_format!("{a}-{b}-{c", [("a", Value::Str("1"))]).unwrap_err() {
+            DynamicFormatError {
+                kind: KeyError { key, .. },
+                ..
+            } => assert_eq!(key, "b"),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_format_args_implicit() {
+        assert_eq!(
+            dynamic_format_args("{} {} {}", &[Value::Str("a"), Value::Str("b"), Value::Str("c")])
+                .unwrap(),
+            "a b c".to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_args_explicit() {
+        assert_eq!(
+            dynamic_format_args("{1} {0}", &[Value::Str("a"), Value::Str("b")]).unwrap(),
+            "b a".to_string()
         );
     }
+
+    #[test]
+    fn test_format_args_mixed_does_not_advance_on_explicit() {
+        assert_eq!(
+            dynamic_format_args("{1} {} {0}", &[Value::Str("a"), Value::Str("b")]).unwrap(),
+            "b a a".to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_args_with_spec() {
+        assert_eq!(
+            dynamic_format_args("{0:>5}", &[Value::Int(7)]).unwrap(),
+            format!("{:>5}", 7)
+        );
+    }
+
+    #[test]
+    fn test_format_args_index_error() {
+        match *dynamic_format_args("{1}", &[Value::Str("a")]).unwrap_err() {
+            DynamicFormatError {
+                kind: IndexError { index, len },
+                ..
+            } => {
+                assert_eq!(index, 1);
+                assert_eq!(len, 1);
+            }
+            _ => unreachable!(),
+        }
+        match *dynamic_format_args("{}{}", &[Value::Str("a")]).unwrap_err() {
+            DynamicFormatError {
+                kind: IndexError { index, len },
+                ..
+            } => {
+                assert_eq!(index, 1);
+                assert_eq!(len, 1);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_format_args_invalid_key_error() {
+        match *dynamic_format_args("{abc}", &[Value::Str("a")]).unwrap_err() {
+            DynamicFormatError {
+                kind: InvalidKeyError { key },
+                ..
+            } => {
+                assert_eq!(key, "abc");
+            }
+            _ => unreachable!(),
+        }
+    }
 }